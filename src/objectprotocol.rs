@@ -8,7 +8,7 @@ use ffi;
 use err::{PyErr, PyResult, PyDowncastError, self};
 use python::{Python, ToPyPointer, PyDowncastFrom, PyClone};
 use pointer::PyObject;
-use objects::{PyInstance, PyDict, PyString, PyIterator, PyType};
+use objects::{PyInstance, PyDict, PyList, PyString, PyIterator, PyType};
 use conversion::{ToPyObject, IntoPyTuple, FromPyObject};
 use instance::PyObjectWithToken;
 
@@ -61,6 +61,28 @@ pub trait ObjectProtocol {
     fn rich_compare<O>(&self, other: O, compare_op: ::CompareOp) -> PyResult<PyObject>
         where O: ToPyObject;
 
+    /// Compares two Python objects, returning whether a single comparison
+    /// holds.
+    ///
+    /// Depending on the value of `compare_op`, equivalent to one of the
+    /// following Python expressions:
+    ///   * CompareOp::Eq: `self == other`
+    ///   * CompareOp::Ne: `self != other`
+    ///   * CompareOp::Lt: `self < other`
+    ///   * CompareOp::Le: `self <= other`
+    ///   * CompareOp::Gt: `self > other`
+    ///   * CompareOp::Ge: `self >= other`
+    ///
+    /// As with `PyObject_RichCompareBool`, `Eq` reports `true` and `Ne`
+    /// reports `false` when `self` and `other` are the same object.
+    ///
+    /// Unlike `compare`, this does not require the objects to be totally
+    /// ordered: it reports the truth value of exactly the operator
+    /// requested, which is the right primitive for partially-ordered types
+    /// (e.g. sets, or floats containing NaN).
+    fn rich_compare_bool<O>(&self, other: O, compare_op: ::CompareOp) -> PyResult<bool>
+        where O: ToPyObject;
+
     /// Compute the string representation of self.
     /// This is equivalent to the Python expression 'repr(self)'.
     fn repr(&self) -> PyResult<&PyString>;
@@ -83,6 +105,24 @@ pub trait ObjectProtocol {
                       -> PyResult<&PyInstance>
         where A: IntoPyTuple;
 
+    /// Calls the object without arguments.
+    /// This is equivalent to the Python expression: 'self()'
+    fn call0(&self) -> PyResult<&PyInstance>;
+
+    /// Calls the object with only positional arguments.
+    /// This is equivalent to the Python expression: 'self(*args)'
+    fn call1<A>(&self, args: A) -> PyResult<&PyInstance>
+        where A: IntoPyTuple;
+
+    /// Calls a method on the object without arguments.
+    /// This is equivalent to the Python expression: 'self.name()'
+    fn call_method0(&self, name: &str) -> PyResult<&PyInstance>;
+
+    /// Calls a method on the object with only positional arguments.
+    /// This is equivalent to the Python expression: 'self.name(*args)'
+    fn call_method1<A>(&self, name: &str, args: A) -> PyResult<&PyInstance>
+        where A: IntoPyTuple;
+
     /// Retrieves the hash code of the object.
     /// This is equivalent to the Python expression: 'hash(self)'
     fn hash(&self) -> PyResult<::Py_hash_t>;
@@ -120,6 +160,10 @@ pub trait ObjectProtocol {
     /// Gets the Python type object for this object's type.
     fn get_type(&self) -> &PyType;
 
+    /// Returns the list of attribute names of this object.
+    /// This is equivalent to the Python expression: 'dir(self)'
+    fn dir(&self) -> &PyList;
+
     /// Casts the PyObject to a concrete Python object type.
     /// Fails with `PyDowncastError` if the object is not of the expected type.
     fn cast_as<'a, D>(&'a self) -> Result<&'a D, PyDowncastError<'a>>
@@ -225,6 +269,19 @@ impl<T> ObjectProtocol for T where T: PyObjectWithToken + ToPyPointer {
         }
     }
 
+    fn rich_compare_bool<O>(&self, other: O, compare_op: ::CompareOp)
+                            -> PyResult<bool> where O: ToPyObject {
+        other.with_borrowed_ptr(self.token(), |other| unsafe {
+            let result = ffi::PyObject_RichCompareBool(
+                self.as_ptr(), other, compare_op as c_int);
+            if result < 0 {
+                Err(PyErr::fetch(self.token()))
+            } else {
+                Ok(result != 0)
+            }
+        })
+    }
+
     #[inline]
     fn repr(&self) -> PyResult<&PyString> {
         unsafe {
@@ -274,6 +331,50 @@ impl<T> ObjectProtocol for T where T: PyObjectWithToken + ToPyPointer {
         })
     }
 
+    #[inline]
+    fn call0(&self) -> PyResult<&PyInstance> {
+        unsafe {
+            self.token().cast_from_borrowed_ptr_or_err(
+                ffi::PyObject_CallObject(self.as_ptr(), std::ptr::null_mut()))
+        }
+    }
+
+    #[inline]
+    fn call1<A>(&self, args: A) -> PyResult<&PyInstance>
+        where A: IntoPyTuple
+    {
+        let t = args.into_tuple(self.token());
+        let result = unsafe {
+            self.token().cast_from_borrowed_ptr_or_err(
+                ffi::PyObject_CallObject(self.as_ptr(), t.as_ptr()))
+        };
+        self.token().release(t);
+        result
+    }
+
+    #[inline]
+    fn call_method0(&self, name: &str) -> PyResult<&PyInstance> {
+        name.with_borrowed_ptr(self.token(), |name| unsafe {
+            let ptr = ffi::PyObject_GetAttr(self.as_ptr(), name);
+            self.token().cast_from_borrowed_ptr_or_err(
+                ffi::PyObject_CallObject(ptr, std::ptr::null_mut()))
+        })
+    }
+
+    #[inline]
+    fn call_method1<A>(&self, name: &str, args: A) -> PyResult<&PyInstance>
+        where A: IntoPyTuple
+    {
+        name.with_borrowed_ptr(self.token(), |name| unsafe {
+            let t = args.into_tuple(self.token());
+            let ptr = ffi::PyObject_GetAttr(self.as_ptr(), name);
+            let result = self.token().cast_from_borrowed_ptr_or_err(
+                ffi::PyObject_CallObject(ptr, t.as_ptr()));
+            self.token().release(t);
+            result
+        })
+    }
+
     #[inline]
     fn hash(&self) -> PyResult<ffi::Py_hash_t> {
         let v = unsafe { ffi::PyObject_Hash(self.as_ptr()) };
@@ -353,6 +454,14 @@ impl<T> ObjectProtocol for T where T: PyObjectWithToken + ToPyPointer {
         }
     }
 
+    #[inline]
+    fn dir(&self) -> &PyList {
+        unsafe {
+            self.token().cast_from_owned_ptr_or_panic(
+                ffi::PyObject_Dir(self.as_ptr()))
+        }
+    }
+
     #[inline]
     fn cast_as<'a, D>(&'a self) -> Result<&'a D, PyDowncastError<'a>>
         where D: PyDowncastFrom,
@@ -408,4 +517,65 @@ mod test {
         let s = PyString::downcast_from(v.as_ref(py)).unwrap();
         assert_eq!(format!("{}", s), "Hello\n");
     }
+
+    #[test]
+    fn test_rich_compare_bool_partial_order() {
+        use super::ObjectProtocol;
+        use CompareOp;
+
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let a = py.eval("{1, 2}", None, None).unwrap();
+        let b = py.eval("{3, 4}", None, None).unwrap();
+        // Disjoint sets are neither '<' nor '>=' each other: the
+        // single-operator check must report `false` rather than raising,
+        // unlike `compare()`'s total-order assumption.
+        assert_eq!(a.rich_compare_bool(b, CompareOp::Lt).unwrap(), false);
+        assert_eq!(a.rich_compare_bool(b, CompareOp::Ge).unwrap(), false);
+        assert_eq!(a.rich_compare_bool(b, CompareOp::Eq).unwrap(), false);
+    }
+
+    #[test]
+    fn test_dir() {
+        use super::ObjectProtocol;
+
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let obj = py.eval("object()", None, None).unwrap();
+        let names: Vec<String> = obj.dir().iter()
+            .map(|name| name.extract().unwrap())
+            .collect();
+        assert!(names.contains(&"__class__".to_string()));
+        assert!(names.contains(&"__repr__".to_string()));
+    }
+
+    #[test]
+    fn test_call0_call1() {
+        use super::ObjectProtocol;
+
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let list_type = py.eval("list", None, None).unwrap();
+        let empty: Vec<i32> = list_type.call0().unwrap().extract().unwrap();
+        assert_eq!(empty, Vec::<i32>::new());
+
+        let from_range: Vec<i32> = list_type.call1((py.eval("range(3)", None, None).unwrap(),))
+            .unwrap().extract().unwrap();
+        assert_eq!(from_range, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_call_method0_call_method1() {
+        use super::ObjectProtocol;
+
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let list = py.eval("[3, 1, 2]", None, None).unwrap();
+        list.call_method0("sort").unwrap();
+        let sorted: Vec<i32> = list.extract().unwrap();
+        assert_eq!(sorted, vec![1, 2, 3]);
+
+        let index: i32 = list.call_method1("index", (2,)).unwrap().extract().unwrap();
+        assert_eq!(index, 1);
+    }
 }
@@ -1,10 +1,14 @@
+use std;
 use std::borrow::Cow;
+use std::str;
 
-use err::PyResult;
+use ffi;
+use err::{PyErr, PyResult};
 use pointer::PyObject;
 use objects::{PyInstance, PyString};
 use objectprotocol::ObjectProtocol;
-use python::Python;
+use python::{Python, ToPyPointer};
+use instance::PyObjectWithToken;
 use conversion::{ToPyObject, IntoPyObject, RefFromPyObject};
 
 /// Converts Rust `str` to Python object.
@@ -54,10 +58,36 @@ impl<'a> IntoPyObject for &'a String {
 
 /// Allows extracting strings from Python objects.
 /// Accepts Python `str` and `unicode` objects.
+///
+/// On CPython 3.3 and later, this takes a fast path that borrows directly
+/// from the UTF-8 buffer that CPython caches on the `str` object, avoiding
+/// an allocation. The borrow is tied to the `'source` lifetime of `ob`, so
+/// it cannot outlive the underlying Python object.
 impl<'source> ::FromPyObject<'source> for Cow<'source, str>
 {
     fn extract(ob: &'source PyInstance) -> PyResult<Self>
     {
+        #[cfg(Py_3)]
+        {
+            let ptr = ob.as_ptr();
+            if unsafe { ffi::PyUnicode_Check(ptr) } != 0 {
+                let mut size: ffi::Py_ssize_t = 0;
+                let data = unsafe { ffi::PyUnicode_AsUTF8AndSize(ptr, &mut size) };
+                if data.is_null() {
+                    // e.g. the string contains surrogates and has no UTF-8
+                    // representation; propagate the UnicodeEncodeError
+                    // CPython has already set rather than falling back.
+                    return Err(PyErr::fetch(ob.token()));
+                }
+                let slice = unsafe {
+                    std::slice::from_raw_parts(data as *const u8, size as usize)
+                };
+                // PyUnicode_AsUTF8AndSize only ever returns a valid UTF-8
+                // buffer (or null, handled above).
+                let s = unsafe { str::from_utf8_unchecked(slice) };
+                return Ok(Cow::Borrowed(s));
+            }
+        }
         try!(ob.cast_as::<PyString>()).to_string()
     }
 }
@@ -77,3 +107,186 @@ impl RefFromPyObject for str {
         Ok(f(&s))
     }
 }
+
+// Filesystem-path conversions.
+//
+// These round-trip through Python's filesystem encoding rather than plain
+// UTF-8, so that paths that are not valid Unicode (arbitrary bytes on Unix,
+// unpaired surrogates on Windows) survive the trip intact.
+
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+#[cfg(unix)]
+fn osstr_to_pyobject(py: Python, s: &OsStr) -> PyObject {
+    use std::os::raw::c_char;
+    use std::os::unix::ffi::OsStrExt;
+    let bytes = s.as_bytes();
+    unsafe {
+        PyObject::from_owned_ptr(py, ffi::PyUnicode_DecodeFSDefaultAndSize(
+            bytes.as_ptr() as *const c_char, bytes.len() as ffi::Py_ssize_t))
+    }
+}
+
+#[cfg(windows)]
+fn osstr_to_pyobject(py: Python, s: &OsStr) -> PyObject {
+    use std::os::windows::ffi::OsStrExt;
+    let wchars: Vec<u16> = s.encode_wide().collect();
+    unsafe {
+        PyObject::from_owned_ptr(py, ffi::PyUnicode_FromWideChar(
+            wchars.as_ptr(), wchars.len() as ffi::Py_ssize_t))
+    }
+}
+
+#[cfg(unix)]
+fn osstring_from_pyobject(ob: &PyInstance) -> PyResult<OsString> {
+    use std::os::unix::ffi::OsStringExt;
+    let mut bytes_ptr: *mut ffi::PyObject = std::ptr::null_mut();
+    unsafe {
+        if ffi::PyUnicode_FSConverter(ob.as_ptr(), &mut bytes_ptr as *mut _ as *mut _) == 0 {
+            return Err(PyErr::fetch(ob.token()));
+        }
+        let bytes = PyObject::from_owned_ptr(ob.token(), bytes_ptr);
+        let buf = ffi::PyBytes_AsString(bytes.as_ptr());
+        let len = ffi::PyBytes_Size(bytes.as_ptr());
+        let slice = std::slice::from_raw_parts(buf as *const u8, len as usize);
+        Ok(OsString::from_vec(slice.to_vec()))
+    }
+}
+
+#[cfg(windows)]
+fn osstring_from_pyobject(ob: &PyInstance) -> PyResult<OsString> {
+    use std::os::windows::ffi::OsStringExt;
+    let mut size: ffi::Py_ssize_t = 0;
+    unsafe {
+        let wchars = ffi::PyUnicode_AsWideCharString(ob.as_ptr(), &mut size);
+        if wchars.is_null() {
+            return Err(PyErr::fetch(ob.token()));
+        }
+        let slice = std::slice::from_raw_parts(wchars, size as usize);
+        let result = OsString::from_wide(slice);
+        ffi::PyMem_Free(wchars as *mut std::os::raw::c_void);
+        Ok(result)
+    }
+}
+
+/// Converts Rust `OsStr` to Python object, using Python's filesystem
+/// encoding (`surrogateescape` on Unix, wide chars on Windows) so that
+/// non-Unicode paths survive the round trip.
+impl ToPyObject for OsStr {
+    #[inline]
+    fn to_object(&self, py: Python) -> PyObject {
+        osstr_to_pyobject(py, self)
+    }
+}
+impl<'a> IntoPyObject for &'a OsStr {
+    #[inline]
+    fn into_object(self, py: Python) -> PyObject {
+        osstr_to_pyobject(py, self)
+    }
+}
+
+/// Converts Rust `OsString` to Python object.
+/// See the `OsStr` conversion for details.
+impl ToPyObject for OsString {
+    #[inline]
+    fn to_object(&self, py: Python) -> PyObject {
+        osstr_to_pyobject(py, self.as_os_str())
+    }
+}
+impl IntoPyObject for OsString {
+    #[inline]
+    fn into_object(self, py: Python) -> PyObject {
+        osstr_to_pyobject(py, self.as_os_str())
+    }
+}
+
+/// Allows extracting `OsString` from Python objects.
+/// On Unix, accepts `str`, `bytes` and `os.PathLike` objects, encoded via
+/// Python's filesystem encoding; on Windows, accepts only `str`.
+pyobject_extract!(py, obj to OsString => {
+    osstring_from_pyobject(obj)
+});
+
+/// Converts Rust `Path` to Python object.
+/// See the `OsStr` conversion for details.
+impl ToPyObject for Path {
+    #[inline]
+    fn to_object(&self, py: Python) -> PyObject {
+        osstr_to_pyobject(py, self.as_os_str())
+    }
+}
+impl<'a> IntoPyObject for &'a Path {
+    #[inline]
+    fn into_object(self, py: Python) -> PyObject {
+        osstr_to_pyobject(py, self.as_os_str())
+    }
+}
+
+/// Converts Rust `PathBuf` to Python object.
+/// See the `OsStr` conversion for details.
+impl ToPyObject for PathBuf {
+    #[inline]
+    fn to_object(&self, py: Python) -> PyObject {
+        osstr_to_pyobject(py, self.as_os_str())
+    }
+}
+impl IntoPyObject for PathBuf {
+    #[inline]
+    fn into_object(self, py: Python) -> PyObject {
+        osstr_to_pyobject(py, self.as_os_str())
+    }
+}
+
+/// Allows extracting `PathBuf` from Python objects.
+/// See the `OsString` extraction for the accepted types.
+pyobject_extract!(py, obj to PathBuf => {
+    osstring_from_pyobject(obj).map(PathBuf::from)
+});
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+    use std::path::PathBuf;
+    use conversion::ToPyObject;
+    use objectprotocol::ObjectProtocol;
+    use python::Python;
+
+    #[test]
+    fn test_cow_str_extract_borrows() {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let obj = "hello".to_object(py);
+        let s: Cow<str> = obj.as_ref(py).extract().unwrap();
+        assert_eq!(&*s, "hello");
+        #[cfg(Py_3)]
+        assert!(match s { Cow::Borrowed(_) => true, Cow::Owned(_) => false });
+    }
+
+    #[test]
+    fn test_path_roundtrip_ascii() {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let path = PathBuf::from("/tmp/example.txt");
+        let obj = path.to_object(py);
+        let roundtripped: PathBuf = obj.as_ref(py).extract().unwrap();
+        assert_eq!(path, roundtripped);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_path_roundtrip_non_utf8() {
+        use std::ffi::{OsStr, OsString};
+        use std::os::unix::ffi::OsStrExt;
+
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let path = PathBuf::from(OsStr::from_bytes(b"/tmp/\xff"));
+        let obj = path.to_object(py);
+        let roundtripped: PathBuf = obj.as_ref(py).extract().unwrap();
+        assert_eq!(path, roundtripped);
+
+        let os_string: OsString = obj.as_ref(py).extract().unwrap();
+        assert_eq!(OsStr::new(&os_string), path.as_os_str());
+    }
+}